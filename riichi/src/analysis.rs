@@ -1,5 +1,7 @@
 pub mod decomp;
+pub mod efficiency;
 pub mod irregular;
+pub mod shanten;
 
 use std::fmt::{Display, Formatter};
 
@@ -10,7 +12,9 @@ use crate::{
 };
 pub use self::{
     decomp::{Decomposer, RegularWait},
+    efficiency::{rank_discards, DiscardSuggestion},
     irregular::{IrregularWait, detect_irregular_wait},
+    shanten::ShantenInfo,
 };
 
 #[derive(Copy, Clone, Debug)]
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fmt::{Display, Formatter};
 
 use itertools::Itertools;
@@ -224,7 +225,7 @@ impl Decomposer<'_> {
     }
 
     /// Iterates through all regular hand decompositions.
-    pub fn iter(&self) -> impl Iterator<Item=RegularWait> + '_ {
+    pub fn iter(&self) -> RegularWaits<'_> {
         let suit_x =
             self.c_for_suit
                 .iter()
@@ -233,7 +234,7 @@ impl Decomposer<'_> {
                 .fold(4, |suit_x, (suit, len)| {
                     if len != 0 { suit_x } else if suit_x == 4 { suit as u8 } else { 5 }
                 });
-        [
+        let chain = [
             (0, [1, 2, 3]),
             (1, [0, 2, 3]),
             (2, [0, 1, 3]),
@@ -257,19 +258,94 @@ impl Decomposer<'_> {
                     extend_partial_iter(chain, suits_c[2], &self.c_for_suit[suits_c[2] as usize]);
 
                 chain.flat_map(RegularWait::complete)
+            });
+        RegularWaits { inner: Box::new(chain) }
+    }
+
+    /// Collects all regular decompositions into an owning iterator that no longer borrows this
+    /// decomposer. The decompositions are computed eagerly up front, so the iterator can be stored
+    /// in a struct field or passed across function boundaries.
+    pub fn into_waits(&self) -> RegularWaitsOwned {
+        RegularWaitsOwned { inner: self.iter().collect_vec().into_iter() }
+    }
+
+    /// Folds the decomposition stream into the canonical ukeire set: a map from each accepted
+    /// (winning) tile to every hand shape that accepts it.
+    ///
+    /// A [`RyanmenBoth`][WaitingKind::RyanmenBoth] wait contributes its two winning tiles to two
+    /// distinct groups (already handled by [`RegularWait::complete`]). Duplicate shapes landing in
+    /// the same group are removed, so each group lists distinct decompositions only. The grouping
+    /// is done in a single pass via itertools' `into_grouping_map_by`.
+    pub fn waits(&self) -> BTreeMap<Tile, Vec<RegularWait>> {
+        self.iter()
+            .into_grouping_map_by(|wait| wait.waiting_tile)
+            .collect::<Vec<_>>()
+            .into_iter()
+            .map(|(tile, group)| {
+                let shapes = group
+                    .into_iter()
+                    .unique_by(|w| (w.raw_groups, w.pair, w.waiting_kind, w.pattern_tile))
+                    .collect_vec();
+                (tile, shapes)
             })
+            .collect()
     }
+
+    /// Returns the `k` highest-scoring decompositions under the caller-supplied key, without
+    /// sorting the full stream.
+    ///
+    /// This maintains a bounded heap of size `k` while consuming the lazy [`iter`](Self::iter),
+    /// keeping memory at `O(k)` even for pathological hands like pure nine gates
+    /// (`1112345678999m`) that produce many decompositions. Scoring relies solely on `f`, since
+    /// [`RegularWait`]'s own `Ord` is test-only.
+    pub fn best_by_key<K: Ord>(&self, k: usize, f: impl Fn(&RegularWait) -> K) -> Vec<RegularWait> {
+        self.iter().k_largest_by_key(k, |wait| f(wait)).collect_vec()
+    }
+}
+
+/// A nameable, movable iterator over the regular decompositions of a loaded [`Decomposer`].
+///
+/// This borrows the decomposer's cached intermediate results (tied to the borrow of the decomposer).
+/// For an iterator that outlives the borrow, use [`Decomposer::into_waits`].
+pub struct RegularWaits<'a> {
+    inner: Box<dyn Iterator<Item=RegularWait> + 'a>,
+}
+
+impl Iterator for RegularWaits<'_> {
+    type Item = RegularWait;
+    fn next(&mut self) -> Option<RegularWait> { self.inner.next() }
 }
 
-/*
-// TODO(summivox): rust (impl Trait in type aliases)
-impl<'a> IntoIterator for &Decomposer<'a> {
+impl<'a> IntoIterator for &'a Decomposer<'_> {
     type Item = RegularWait;
-    type IntoIter = impl Iterator<Item=RegularWait> + 'a;
+    type IntoIter = RegularWaits<'a>;
+    fn into_iter(self) -> RegularWaits<'a> { self.iter() }
+}
 
-    fn into_iter(self) -> Self::IntoIter { self.iter() }
+/// An owning iterator over regular decompositions, snapshotted from a [`Decomposer`] so it no
+/// longer borrows it. See [`Decomposer::into_waits`].
+pub struct RegularWaitsOwned {
+    inner: std::vec::IntoIter<RegularWait>,
+}
+
+impl Iterator for RegularWaitsOwned {
+    type Item = RegularWait;
+    fn next(&mut self) -> Option<RegularWait> { self.inner.next() }
+}
+
+/// Decomposes many hands in parallel on rayon's thread pool, with a fresh [`Decomposer`] per hand.
+///
+/// The `CTable`/`WTable` live behind `&'static` [`OnceCell`]s and are shared freely across threads,
+/// but each hand gets a fresh [`Decomposer`] so that the per-instance mutable buffers (`keys`,
+/// `c_for_suit`) are never shared. Since [`RegularWait`] is `Copy` and the tables are immutable, no
+/// locking is needed — callers can saturate all cores for large decomposition workloads.
+pub fn par_decompose(hands: &[TileSet34]) -> Vec<Vec<RegularWait>> {
+    use rayon::prelude::*;
+    hands
+        .par_iter()
+        .map(|hand| Decomposer::new().with_tile_set(hand).iter().collect())
+        .collect()
 }
-*/
 
 // Note: Below are all implementation details. Conveniently, `RegularWait` can be directly used to
 // represent intermediate results of a hand decomposition.
@@ -0,0 +1,52 @@
+use crate::common::*;
+
+use super::{Decomposer, ShantenInfo};
+
+/// The result of evaluating one candidate discard: the shanten and total tile acceptance of the
+/// hand that remains after discarding [`discard`](Self::discard).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DiscardSuggestion {
+    /// The tile to discard.
+    pub discard: Tile,
+    /// The shanten number of the hand after discarding.
+    pub shanten: i8,
+    /// The number of tiles (counting live copies) that lower the post-discard shanten.
+    pub ukeire: u32,
+    /// The set of accepting tiles after discarding.
+    pub acceptance: TileMask34,
+}
+
+/// Ranks every legal discard of `hand` (with `num_melds` already-called melds) by tile acceptance.
+///
+/// For each tile kind present in the closed hand, the post-discard 3N+1 hand is evaluated with
+/// [`ShantenInfo`], and the results are returned sorted by lowest shanten first, then by highest
+/// ukeire. This is the "best discard by tile acceptance" guidance bot authors and trainers need,
+/// without re-implementing the 34-tile loop over [`Decomposer`].
+pub fn rank_discards(
+    decomposer: &Decomposer,
+    hand: &TileSet34,
+    num_melds: u8,
+) -> Vec<DiscardSuggestion> {
+    let keys = hand.packed_34();
+    let mut suggestions = Vec::new();
+    for kind in 0..34u8 {
+        let tile = Tile::from_encoding(kind).unwrap();
+        if hand[tile] == 0 { continue; }
+
+        let mut after = keys;
+        let suit = (kind / 9) as usize;
+        let pos = (kind % 9) as u32;
+        after[suit] -= 1 << (3 * pos);
+
+        let info = ShantenInfo::from_keys(decomposer, &after, num_melds);
+        suggestions.push(DiscardSuggestion {
+            discard: tile,
+            shanten: info.shanten,
+            ukeire: info.acceptance_count.iter().map(|&c| c as u32).sum(),
+            acceptance: info.acceptance,
+        });
+    }
+    suggestions.sort_by(|a, b|
+        a.shanten.cmp(&b.shanten).then(b.ukeire.cmp(&a.ukeire)));
+    suggestions
+}
@@ -0,0 +1,216 @@
+use crate::common::*;
+
+use super::Decomposer;
+
+/// Tells how far a hand is from winning (shanten 向聴数) and which tiles bring it closer
+/// (ukeire 受け入れ).
+///
+/// The shanten number is the minimum number of tile exchanges required to reach tenpai. A ready
+/// (tenpai) hand has shanten `0`; a complete hand has shanten `-1`. This struct folds the three
+/// standard hand forms (regular `4` sets `+` pair, Chiitoitsu 七対子, and Kokushi 国士無双) and
+/// reports the minimum, together with the per-form breakdown for callers that need it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct ShantenInfo {
+    /// The overall shanten number, i.e. the minimum across all three forms.
+    pub shanten: i8,
+    /// Shanten of the regular (4 sets + pair) form.
+    pub regular: i8,
+    /// Shanten of the Chiitoitsu (七対子) form.
+    pub chiitoitsu: i8,
+    /// Shanten of the Kokushi (国士無双) form.
+    pub kokushi: i8,
+    /// The set of tiles that, when drawn, lower the overall shanten (ukeire).
+    pub acceptance: TileMask34,
+    /// For each accepting tile kind, the number of live copies still available
+    /// (`4 -` copies already in hand); `0` for non-accepting kinds.
+    pub acceptance_count: [u8; 34],
+}
+
+impl ShantenInfo {
+    /// Computes the shanten and ukeire of a hand given its [octal-packed][packed] suit keys and the
+    /// number of melds already called (which count as complete sets towards the regular form).
+    ///
+    /// [packed]: TileSet34::packed_34
+    pub fn from_keys(decomposer: &Decomposer, keys: &[u32; 4], num_melds: u8) -> Self {
+        let counts = counts_from_keys(keys);
+
+        let regular = decomposer.regular_shanten(keys, num_melds);
+        let chiitoitsu = chiitoitsu_shanten(&counts);
+        let kokushi = kokushi_shanten(&counts);
+        let shanten = regular.min(chiitoitsu).min(kokushi);
+
+        // Ukeire: a kind is accepting iff drawing one more copy lowers the overall shanten.
+        let mut acceptance = TileMask34::default();
+        let mut acceptance_count = [0u8; 34];
+        let mut probe = counts;
+        for kind in 0..34u8 {
+            if counts[kind as usize] >= 4 { continue; }
+            probe[kind as usize] += 1;
+            let probe_keys = keys_from_counts(&probe);
+            let after = decomposer.regular_shanten(&probe_keys, num_melds)
+                .min(chiitoitsu_shanten(&probe))
+                .min(kokushi_shanten(&probe));
+            probe[kind as usize] -= 1;
+            if after < shanten {
+                acceptance.0 |= 1u64 << kind;
+                acceptance_count[kind as usize] = 4 - counts[kind as usize];
+            }
+        }
+
+        Self { shanten, regular, chiitoitsu, kokushi, acceptance, acceptance_count }
+    }
+}
+
+impl Decomposer<'_> {
+    /// Computes the regular-form (4 sets `+` pair) shanten number of a hand, given its
+    /// [octal-packed][packed] suit keys and the number of melds already called.
+    ///
+    /// Let `m` be the number of complete sets found in the closed portion and `p` the number of
+    /// partial blocks (taatsu / pair candidates), under the block cap `melds + m + p <= 5`. Then
+    /// `shanten = 8 - 2 * (melds + m) - p`, with the usual `+1` penalty when five blocks are
+    /// claimed but none of them is a pair.
+    ///
+    /// [packed]: TileSet34::packed_34
+    pub fn regular_shanten(&self, keys: &[u32; 4], num_melds: u8) -> i8 {
+        let mut counts = counts_from_keys(keys);
+        let mut best = 8 - 2 * num_melds as i8;
+        decompose_regular(&mut counts, 0, num_melds as i8, 0, false, &mut best);
+        best
+    }
+}
+
+/// Depth-first enumeration of regular decompositions, tracking the best (lowest) shanten.
+///
+/// `sets` already includes the called melds; the total block budget (`sets + partials`) is capped
+/// at five. `has_pair` records whether any pair block has been claimed, which controls the
+/// five-block penalty.
+fn decompose_regular(
+    counts: &mut [u8; 34],
+    start: usize,
+    sets: i8,
+    partials: i8,
+    has_pair: bool,
+    best: &mut i8,
+) {
+    let mut i = start;
+    while i < 34 && counts[i] == 0 { i += 1; }
+
+    if i == 34 || sets + partials == 5 {
+        let blocks = sets + partials;
+        let mut shanten = 8 - 2 * sets - partials;
+        if blocks == 5 && !has_pair { shanten += 1; }
+        if shanten < *best { *best = shanten; }
+        return;
+    }
+
+    let suited = i < 27;
+    let num = i % 9;
+
+    // Complete set: triplet.
+    if counts[i] >= 3 {
+        counts[i] -= 3;
+        decompose_regular(counts, i, sets + 1, partials, has_pair, best);
+        counts[i] += 3;
+    }
+    // Complete set: sequence.
+    if suited && num <= 6 && counts[i + 1] > 0 && counts[i + 2] > 0 {
+        counts[i] -= 1; counts[i + 1] -= 1; counts[i + 2] -= 1;
+        decompose_regular(counts, i, sets + 1, partials, has_pair, best);
+        counts[i] += 1; counts[i + 1] += 1; counts[i + 2] += 1;
+    }
+    // Partial: pair (can serve as the head).
+    if counts[i] >= 2 {
+        counts[i] -= 2;
+        decompose_regular(counts, i, sets, partials + 1, true, best);
+        counts[i] += 2;
+    }
+    // Partial: ryanmen / penchan.
+    if suited && num <= 7 && counts[i + 1] > 0 {
+        counts[i] -= 1; counts[i + 1] -= 1;
+        decompose_regular(counts, i, sets, partials + 1, has_pair, best);
+        counts[i] += 1; counts[i + 1] += 1;
+    }
+    // Partial: kanchan.
+    if suited && num <= 6 && counts[i + 2] > 0 {
+        counts[i] -= 1; counts[i + 2] -= 1;
+        decompose_regular(counts, i, sets, partials + 1, has_pair, best);
+        counts[i] += 1; counts[i + 2] += 1;
+    }
+    // Leave the current tile floating and move on.
+    counts[i] -= 1;
+    decompose_regular(counts, i, sets, partials, has_pair, best);
+    counts[i] += 1;
+}
+
+fn chiitoitsu_shanten(counts: &[u8; 34]) -> i8 {
+    let pairs = counts.iter().filter(|&&c| c >= 2).count() as i8;
+    let distinct = counts.iter().filter(|&&c| c >= 1).count() as i8;
+    6 - pairs + (7 - distinct).max(0)
+}
+
+fn kokushi_shanten(counts: &[u8; 34]) -> i8 {
+    let mut kinds = 0i8;
+    let mut has_pair = false;
+    for &i in &TERMINALS_HONORS {
+        if counts[i] >= 1 { kinds += 1; }
+        if counts[i] >= 2 { has_pair = true; }
+    }
+    13 - kinds - has_pair as i8
+}
+
+const TERMINALS_HONORS: [usize; 13] =
+    [0, 8, 9, 17, 18, 26, 27, 28, 29, 30, 31, 32, 33];
+
+fn counts_from_keys(keys: &[u32; 4]) -> [u8; 34] {
+    let mut counts = [0u8; 34];
+    for (suit, &key) in keys.iter().enumerate() {
+        let len = if suit == 3 { 7 } else { 9 };
+        for t in 0..len {
+            counts[suit * 9 + t] = ((key >> (3 * t)) & 0o7) as u8;
+        }
+    }
+    counts
+}
+
+fn keys_from_counts(counts: &[u8; 34]) -> [u32; 4] {
+    let mut keys = [0u32; 4];
+    for (suit, key) in keys.iter_mut().enumerate() {
+        let len = if suit == 3 { 7 } else { 9 };
+        for t in 0..len {
+            *key |= (counts[suit * 9 + t] as u32) << (3 * t);
+        }
+    }
+    keys
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::str::FromStr;
+
+    fn keys(s: &str) -> [u32; 4] { TileSet34::from_iter(tiles_from_str(s)).packed_34() }
+    fn t(s: &str) -> Tile { Tile::from_str(s).unwrap() }
+
+    #[test]
+    fn shanten_forms() {
+        let d = Decomposer::new();
+        // Pure nine gates is a regular tenpai (shanten 0).
+        assert_eq!(ShantenInfo::from_keys(&d, &keys("1112345678999m"), 0).regular, 0);
+        // Six pairs + a single is a Chiitoitsu tenpai.
+        assert_eq!(ShantenInfo::from_keys(&d, &keys("1122334455667m"), 0).chiitoitsu, 0);
+        // All thirteen terminals/honors, no pair: the 13-sided Kokushi tenpai.
+        assert_eq!(ShantenInfo::from_keys(&d, &keys("19m19p19s1234567z"), 0).kokushi, 0);
+    }
+
+    #[test]
+    fn ukeire_of_a_ryanmen_tenpai() {
+        let d = Decomposer::new();
+        // 123456789m 11p 23p waits on 1p / 4p.
+        let info = ShantenInfo::from_keys(&d, &keys("123456789m1123p"), 0);
+        assert_eq!(info.shanten, 0);
+        assert!(info.acceptance.has(t("1p")));
+        assert!(info.acceptance.has(t("4p")));
+        assert!(!info.acceptance.has(t("7p")));
+    }
+}
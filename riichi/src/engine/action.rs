@@ -1,6 +1,7 @@
 use itertools::Itertools;
 use thiserror::Error;
 
+use crate::analysis::Decomposer;
 use crate::common::*;
 use crate::engine::agari::{agari_candidates, AgariInput};
 use crate::model::*;
@@ -122,9 +123,21 @@ pub(crate) fn check_action(
             let tile = tile.to_normal();
 
             if is_last_draw(state) { return Err(CannotKanOnLastDraw); }
-            if under_riichi && !is_ankan_ok_under_riichi(
-                &cache.wait[actor_i].regular, tile) {
-                return Err(InvalidAnkanUnderRiichi(tile));
+            if under_riichi {
+                // Under riichi the discard is forced to be the freshly drawn tile (tsumogiri).
+                let drawn = state.core.draw.unwrap();
+                let mut decomposer = Decomposer::new();
+                if !is_ankan_ok_under_riichi(
+                    &mut decomposer,
+                    &hand,
+                    cache.wait[actor_i].waiting_set,
+                    tile,
+                    drawn,
+                    drawn,
+                    &begin.rules,
+                ) {
+                    return Err(InvalidAnkanUnderRiichi(tile));
+                }
             }
             if let Some(ankan) = Ankan::from_hand(&hand, tile) {
                 ankan.consume_from_hand(&mut hand);
@@ -152,6 +165,30 @@ pub(crate) fn check_action(
                 kakan.consume_from_hand(&mut hand);
                 cache.meld[actor_i] = Some(Meld::Kakan(kakan));
                 cache.update_wait_cache(actor, &hand);
+
+                // The added tile is robbable (chankan 槍槓): any other seat waiting on it may ron.
+                // Unlike Ankan (only the Kokushi special case is robbable), the whole quad of a
+                // Kakan is exposed, so we offer it as a ron source to all three opponents.
+                let robbed = added.to_normal();
+                cache.chankan_tile = Some(robbed);
+                for other in other_players_after(actor) {
+                    let other_i = other.to_usize();
+                    if cache.wait[other_i].waiting_set.has(robbed) {
+                        let agari_input = AgariInput::new(
+                            begin.round_id,
+                            &state,
+                            &cache.wait[other_i],
+                            action,
+                            other,
+                            actor,
+                        );
+                        cache.win[other_i] = agari_candidates(&begin.rules, &agari_input);
+                    } else {
+                        // Clear any stale candidates so non-waiting seats are never offered a ron
+                        // for this robbable tile.
+                        cache.win[other_i].clear();
+                    }
+                }
             } else {
                 return Err(TileNotExist(added));
             }
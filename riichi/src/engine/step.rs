@@ -7,9 +7,12 @@ use crate::{
 use super::{
     utils::*,
     EngineCache,
-    RIICHI_POT
 };
 
+/// Points added to a ron payment per honba counter (tsumibō 積み棒); mirrors the honba bonus
+/// [`distribute_points`] bakes into the winner's gain.
+const HONBA_POINTS_RON: GamePoints = 300;
+
 /// Process normal end-of-turn flow (no abort, no win).
 /// Each change to the state is processed in chronological order, gradually morphing the current
 /// state to the next. This avoids copying the entire state.
@@ -120,12 +123,13 @@ pub(crate) fn next_normal(
             next.draw = Some(wall::kan_draw(&begin.wall, state.core.num_drawn_tail as usize));
             next.num_drawn_tail += 1;
 
-            // Only for Ankan: reveal the next dora indicator immediately.
-            // For Kakan, it will only be revealed at the end of the next turn, in the same way
-            // as Daiminkan (see above).
-            // TODO(summivox): rules (kan-dora)
+            // Only for Ankan: reveal the next dora indicator immediately (under the default
+            // `immediate` kan-dora timing). For Kakan, it will only be revealed at the end of the
+            // next turn, in the same way as Daiminkan (see above).
             if let Action::Ankan(_) = action {
-                next.num_dora_indicators += 1;
+                if begin.rules.choice("kan_dora_timing") == "immediate" {
+                    next.num_dora_indicators += 1;
+                }
             }
         }
 
@@ -208,9 +212,24 @@ pub(crate) fn next_agari(
         }
 
         AgariKind::Ron => {
-            // TODO(summivox): rules (atama-hane)
             let contributor = state.core.action_player;
             let winning_tile = action.tile().unwrap();
+
+            // Some rulesets abort on double/triple ron instead of paying; bail out to the abort
+            // path before any payout when that applies.
+            let num_ron = other_players_after(contributor).into_iter()
+                .filter(|w| matches!(reactions[w.to_usize()], Some(Reaction::RonAgari)))
+                .count();
+            if let Some(reason) = ron_abort_reason(&begin.rules, num_ron) {
+                return next_abort(begin, state, reason, cache);
+            }
+
+            // Under atama-hane (head-bump), only the ron winner closest counterclockwise to the
+            // discarder scores; the rest are discarded even though their reaction was valid. Under
+            // multi-ron, everyone is paid, and the pot (which goes to the first winner) is handed
+            // out in the same counterclockwise order.
+            let head_bump = begin.rules.choice("multi_ron") == "atama_hane";
+
             let mut take_pot = true;
             for winner in other_players_after(contributor) {
                 if let Some(Reaction::RonAgari) = reactions[winner.to_usize()] {
@@ -221,6 +240,7 @@ pub(crate) fn next_agari(
                     for i in 0..4 { delta[i] += agari_result_one.points_delta[i]; }
                     agari_result[winner.to_usize()] = Some(agari_result_one);
                     take_pot = false;
+                    if head_bump { break; }
                 }
             }
         }
@@ -285,27 +305,131 @@ fn finalize_agari(
         contributor,
         best_candidate.scoring.basic_points(),
     );
+
+    // Pao (sekinin-barai 責任払い): if an opponent's enabling call/discard completed a liable
+    // yakuman (daisuushii / daisangen / daiminkan rinshan), that opponent shoulders the hand value.
+    let liable_player = match detect_pao_liable(state, agari_kind, winner, &all_tiles) {
+        Some(liable) if liable != winner => {
+            let gain = delta[winner_i];
+            for player in all_players() {
+                if player != winner { delta[player.to_usize()] = 0; }
+            }
+            match agari_kind {
+                AgariKind::Tsumo => {
+                    // The liable player pays the entire hand value alone.
+                    delta[liable.to_usize()] = -gain;
+                }
+                AgariKind::Ron => {
+                    // Only the base hand value is shared; the honba bonus (tsumibō 積み棒) is paid
+                    // wholly by the discarder. Split the base half-and-half, then pile the honba
+                    // onto the discarder's share alone.
+                    let honba = begin.round_id.honba as GamePoints * HONBA_POINTS_RON;
+                    let base = gain - honba;
+                    let half = base / 2;
+                    delta[liable.to_usize()] -= half;
+                    delta[contributor.to_usize()] -= (base - half) + honba;
+                }
+            }
+            liable
+        }
+        _ => winner,
+    };
+
     if take_pot {
-        delta[winner_i] += begin.pot + RIICHI_POT * num_active_riichi(state) as GamePoints;
+        let riichi_pot = begin.rules.int("riichi_pot") as GamePoints;
+        delta[winner_i] += begin.pot + riichi_pot * num_active_riichi(state) as GamePoints;
     }
     AgariResult {
         winner,
         contributor,
-        liable_player: winner,  // TODO(summivox): rules (pao)
+        liable_player,
         points_delta: delta,
         details: best_candidate,
     }
 }
 
+/// Determines pao (sekinin-barai 責任払い) liability for a winning hand.
+///
+/// Returns the opponent whose enabling call/discard completed a liable yakuman, or `None` when no
+/// pao applies:
+///
+/// - **Daisuushii / Daisangen**: the opponent who fed the last called wind / dragon that completed
+///   the set (a fully concealed set via Ankan carries no feeder, hence no liability).
+/// - **Daiminkan rinshan**: the opponent who discarded the tile called into the Daiminkan whose
+///   rinshan draw then wins by tsumo.
+fn detect_pao_liable(
+    state: &State,
+    agari_kind: AgariKind,
+    winner: Player,
+    all_tiles: &TileSet37,
+) -> Option<Player> {
+    let winner_i = winner.to_usize();
+    let winds = [27usize, 28, 29, 30];
+    let dragons = [31usize, 32, 33];
+    let daisuushii = winds.iter().all(|&k| all_tiles[k] >= 3);
+    let daisangen = dragons.iter().all(|&k| all_tiles[k] >= 3);
+
+    if daisuushii || daisangen {
+        let kinds: &[usize] = if daisuushii { &winds } else { &dragons };
+        // Pao only attaches when the yakuman was completed by a *call*: every relevant triplet must
+        // be an open meld (Pon / Kakan / Daiminkan). If any of them is concealed — an Ankou in the
+        // closed hand or an Ankan — the set was finished by self-draw, so no one is liable. (An
+        // Ankan yields `None` from `meld_called_tile`, so it never counts as open here.)
+        let all_open = kinds.iter().all(|&k|
+            state.melds[winner_i].iter().any(|meld|
+                meld_called_tile(meld).map_or(false, |t| t.encoding() as usize == k)));
+        if all_open {
+            // Melds are appended in call order, so the last open meld of a relevant tile is the one
+            // that brought the set to completion and thus carries the liability.
+            let liable = state.melds[winner_i].iter().rev().find_map(|meld| {
+                let kind = meld_called_tile(meld)?.encoding() as usize;
+                kinds.contains(&kind).then(|| meld_source(winner, meld)).flatten()
+            });
+            if liable.is_some() { return liable; }
+        }
+    }
+
+    if agari_kind == AgariKind::Tsumo {
+        if let Some(meld @ Meld::Daiminkan(_)) = state.core.incoming_meld {
+            return meld_source(winner, &meld);
+        }
+    }
+    None
+}
+
+/// The (normalized) tile that was called to form an open meld, or `None` for a concealed Ankan.
+fn meld_called_tile(meld: &Meld) -> Option<Tile> {
+    match meld {
+        Meld::Chii(chii) => Some(chii.called.to_normal()),
+        Meld::Pon(pon) => Some(pon.called.to_normal()),
+        Meld::Kakan(kakan) => Some(kakan.added.to_normal()),
+        Meld::Daiminkan(daiminkan) => Some(daiminkan.called.to_normal()),
+        Meld::Ankan(_) => None,
+    }
+}
+
+/// The player who discarded the tile an open meld was called from, or `None` for a concealed Ankan.
+fn meld_source(owner: Player, meld: &Meld) -> Option<Player> {
+    let relative = match meld {
+        Meld::Chii(_) => 3,  // Chii is always called from the player to the left (kamicha).
+        Meld::Pon(pon) => pon.dir(),
+        Meld::Kakan(kakan) => kakan.dir(),
+        Meld::Daiminkan(daiminkan) => daiminkan.dir(),
+        Meld::Ankan(_) => return None,
+    };
+    Some(Player::new(relative).wrapping_add(owner))
+}
+
 pub(crate) fn next_abort(
     begin: &RoundBegin,
     state: &State,
     abort_reason: AbortReason,
     cache: &EngineCache,
 ) -> RoundEnd {
+    let riichi_pot = begin.rules.int("riichi_pot") as GamePoints;
     let mut end = RoundEnd {
         round_result: ActionResult::Abort(abort_reason),
-        pot: begin.pot + (num_active_riichi(state) as GamePoints * RIICHI_POT),
+        pot: begin.pot + (num_active_riichi(state) as GamePoints * riichi_pot),
         points: begin.points,
         ..RoundEnd::default()
     };
@@ -317,14 +441,17 @@ pub(crate) fn next_abort(
     let waiting_renchan = waiting[button.to_usize()] > 0;
     match abort_reason {
         AbortReason::WallExhausted => {
-            end.points_delta = calc_wall_exhausted_delta(waiting);
+            end.points_delta = calc_wall_exhausted_delta(&begin.rules, waiting);
             end.renchan = waiting_renchan;
             end.next_round_id = Some(round_id.next_honba(waiting_renchan));
         }
         AbortReason::NagashiMangan => {
-            end.points_delta = calc_nagashi_mangan_delta(state, button);
-            end.renchan = waiting_renchan;
-            end.next_round_id = Some(round_id.next_honba(waiting_renchan));
+            end.points_delta = calc_nagashi_mangan_delta(&begin.rules, state, button);
+            // Many rulesets grant renchan to a dealer who achieves nagashi mangan regardless of
+            // tenpai; others only keep the dealer on by the usual tenpai rule.
+            end.renchan = waiting_renchan ||
+                (begin.rules.bool("nagashi_mangan_renchan") && is_nagashi_mangan(state, button));
+            end.next_round_id = Some(round_id.next_honba(end.renchan));
         }
 
         AbortReason::NineKinds | AbortReason::FourKan | AbortReason::FourWind |
@@ -1,5 +1,5 @@
 use itertools::Itertools;
-use crate::analysis::RegularWait;
+use crate::analysis::{Decomposer, WaitingInfo};
 
 use crate::common::*;
 use crate::model::*;
@@ -54,14 +54,57 @@ pub fn is_forbidden_swap_call(meld: Meld, discard: Tile) -> bool {
     }
 }
 
+/// Checks whether declaring an Ankan on `ankan` is legal while the actor is under riichi.
+///
+/// This implements the relaxed (wait-preserving) rule: forming the quad is legal iff it leaves the
+/// set of accepting tiles bitwise identical. We remove all four copies of the kan tile from the
+/// hand and recompute the waiting set of the remainder, then compare against the hand's current
+/// waiting set (`current_waiting`). This accepts the strict Koutsu case as a subset while rejecting
+/// quads drawn from a ryanmen/nobetan that would change the wait.
+///
+/// `drawn` is the freshly drawn tile and `discard` the tile that would be discarded. When the drawn
+/// tile is not itself the fourth kan tile, the call is an okuri-kan (送り槓) — pushing the draw
+/// along — which is gated behind the `okuri_kan` ruleset flag since many rulesets forbid it.
+///
 /// <https://riichi.wiki/Kan#Kan_during_riichi>
-pub fn is_ankan_ok_under_riichi(decomps: &[RegularWait], ankan: Tile) -> bool {
-    // TODO(summivox): rules (ankan-riichi, okuri-kan, relaxed-ankan-riichi)
-    // TODO(summivox): okuri-kan (need to also check the discard)
-    // TODO(summivox): relaxed rule (sufficient to not change the set of waiting tiles)
+pub fn is_ankan_ok_under_riichi(
+    decomposer: &mut Decomposer,
+    hand: &TileSet37,
+    current_waiting: TileMask34,
+    ankan: Tile,
+    drawn: Tile,
+    discard: Tile,
+    rules: &Ruleset,
+) -> bool {
     let ankan = ankan.to_normal();
-    decomps.iter().all(|decomp|
-        decomp.groups().any(|group| group == HandGroup::Koutsu(ankan)))
+
+    // Okuri-kan: the drawn tile is not the fourth kan tile, so the player is kanning a tile already
+    // held and discarding the draw. Permitted only when the ruleset opts in.
+    if drawn.to_normal() != ankan {
+        if discard.to_normal() != drawn.to_normal() || !rules.bool("okuri_kan") {
+            return false;
+        }
+    }
+
+    let mut remaining = *hand;
+    if remaining[ankan] < 4 { return false; }
+    remaining[ankan] -= 4;
+    let after = WaitingInfo::from_keys(decomposer, &remaining.packed_34()).waiting_set;
+    after == current_waiting
+}
+
+/// Decides how a ron with `num_ron` simultaneous winners should be resolved, given the ruleset.
+///
+/// Some rulesets abort on double/triple ron instead of paying; others pay everyone (multi-ron) or
+/// only the head-bumped winner (atama-hane). [`next_agari`](super::step::next_agari) consults this
+/// before any payout; when it returns `Some(reason)`, `next_agari` pays no one and dispatches to
+/// [`next_abort`](super::step::next_abort) with that reason instead.
+pub fn ron_abort_reason(rules: &Ruleset, num_ron: usize) -> Option<AbortReason> {
+    match num_ron {
+        2 if rules.bool("abort_double_ron") => Some(AbortReason::DoubleRon),
+        3 if rules.bool("abort_triple_ron") => Some(AbortReason::TripleRon),
+        _ => None,
+    }
 }
 
 /********/
@@ -136,11 +179,12 @@ pub fn is_aborted_four_riichi(state: &State, action: Action) -> bool {
 /// When the wall has been exhausted, returns the points delta for each player as well as if the
 /// button player stays the same in the next round (renchan 連荘).
 pub fn resolve_wall_exhausted(
-    state: &State, waiting: [u8; 4], button: Player) -> ([GamePoints; 4], bool) {
+    rules: &Ruleset, state: &State, waiting: [u8; 4], button: Player,
+) -> ([GamePoints; 4], bool) {
     let renchan = waiting[button.to_usize()] > 0;
-    let delta_nagashi = calc_nagashi_mangan_delta(state, button);
+    let delta_nagashi = calc_nagashi_mangan_delta(rules, state, button);
     if delta_nagashi == [0; 4] {
-        (calc_wall_exhausted_delta(waiting), renchan)
+        (calc_wall_exhausted_delta(rules, waiting), renchan)
     } else {
         (delta_nagashi, renchan)
     }
@@ -149,10 +193,8 @@ pub fn resolve_wall_exhausted(
 /// When the wall has been exhausted and no player has achieved
 /// [`ActionResult::AbortNagashiMangan`], given whether each player is waiting (1) or not (0),
 /// returns the points delta for each player.
-pub fn calc_wall_exhausted_delta(waiting: [u8; 4]) -> [GamePoints; 4] {
-    // TODO(summivox): rules (ten-no-ten points)
-    const NO_WAIT_PENALTY_TOTAL: GamePoints = 3000;
-    let no_wait = NO_WAIT_PENALTY_TOTAL;
+pub fn calc_wall_exhausted_delta(rules: &Ruleset, waiting: [u8; 4]) -> [GamePoints; 4] {
+    let no_wait = rules.int("noten_penalty_total") as GamePoints;
 
     let num_waiting = waiting.into_iter().sum();
     let (down, up) = match num_waiting {
@@ -166,21 +208,56 @@ pub fn calc_wall_exhausted_delta(waiting: [u8; 4]) -> [GamePoints; 4] {
 
 /// When the wall has been exhausted and some player has achieved
 /// [`ActionResult::AbortNagashiMangan`], returns the points delta for each player.
-pub fn calc_nagashi_mangan_delta(state: &State, button: Player) -> [GamePoints; 4] {
-    // TODO(summivox): rules (nagashi-mangan-points)
+pub fn calc_nagashi_mangan_delta(rules: &Ruleset, state: &State, button: Player) -> [GamePoints; 4] {
+    let dealer_total = rules.int("nagashi_mangan_dealer_total") as GamePoints;
+    let dealer_each = rules.int("nagashi_mangan_dealer_each") as GamePoints;
+    let nondealer_total = rules.int("nagashi_mangan_nondealer_total") as GamePoints;
+    let nondealer_each = rules.int("nagashi_mangan_nondealer_each") as GamePoints;
 
     let mut delta = [0; 4];
     for player in all_players() {
         if is_nagashi_mangan(state, player) {
             if player == button {
-                delta[player.to_usize()] += 12000 + 4000;
-                for qq in 0..4 { delta[qq] -= 4000; }
+                delta[player.to_usize()] += dealer_total + dealer_each;
+                for qq in 0..4 { delta[qq] -= dealer_each; }
             } else {
-                delta[player.to_usize()] += 8000 + 2000;
-                delta[button.to_usize()] -= 2000;
-                for qq in 0..4 { delta[qq] -= 2000; }
+                delta[player.to_usize()] += nondealer_total + nondealer_each;
+                delta[button.to_usize()] -= nondealer_each;
+                for qq in 0..4 { delta[qq] -= nondealer_each; }
             }
         }
     }
     delta
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn noten_penalty_is_zero_sum() {
+        let rules = Ruleset::default();
+        for waiting in [[1, 0, 0, 0], [1, 1, 0, 0], [1, 1, 1, 0]] {
+            let delta = calc_wall_exhausted_delta(&rules, waiting);
+            assert_eq!(delta.iter().sum::<GamePoints>(), 0);
+        }
+        // One player waiting collects the whole 3000 pot; the three noten each pay 1000.
+        assert_eq!(calc_wall_exhausted_delta(&rules, [1, 0, 0, 0]), [3000, -1000, -1000, -1000]);
+        // All or none waiting: no exchange.
+        assert_eq!(calc_wall_exhausted_delta(&rules, [0, 0, 0, 0]), [0, 0, 0, 0]);
+        assert_eq!(calc_wall_exhausted_delta(&rules, [1, 1, 1, 1]), [0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn ron_abort_follows_ruleset() {
+        let mut rules = Ruleset::default();
+        // Default (multi-ron) never aborts.
+        assert_eq!(ron_abort_reason(&rules, 2), None);
+        assert_eq!(ron_abort_reason(&rules, 3), None);
+
+        rules.set_bool("abort_double_ron", true).set_bool("abort_triple_ron", true);
+        assert_eq!(ron_abort_reason(&rules, 1), None);
+        assert_eq!(ron_abort_reason(&rules, 2), Some(AbortReason::DoubleRon));
+        assert_eq!(ron_abort_reason(&rules, 3), Some(AbortReason::TripleRon));
+    }
+}
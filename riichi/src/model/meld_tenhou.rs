@@ -0,0 +1,180 @@
+//! Conversion between this crate's [`Meld`] and Tenhou's compact 16-bit meld field, so logs and
+//! bots speaking that format can round-trip.
+
+use crate::common::*;
+
+use super::*;
+
+impl Meld {
+    /// Decodes a Tenhou 16-bit meld field into a [`Meld`], given the calling player `from`.
+    ///
+    /// Returns `None` if the bit pattern does not describe a valid meld.
+    ///
+    /// The bit layout follows Tenhou's convention: the low two bits give the relative direction of
+    /// the tile's source (`0` = self, i.e. Ankan), and the remaining bits encode the tiles in
+    /// 136-tile space plus which of them was the called tile.
+    pub fn from_tenhou(d: u16, from: Player) -> Option<Meld> {
+        let relative = (d & 0x3) as u8;
+
+        if d & 0x4 != 0 {
+            // Chii: three in-suit offsets, then `base_and_called` above bit 10.
+            let offsets = [(d >> 3) & 0x3, (d >> 5) & 0x3, (d >> 7) & 0x3];
+            let base_and_called = d >> 10;
+            let called = (base_and_called % 3) as usize;
+            let mut base = base_and_called / 3;
+            // Remap the 7-per-suit sequence base into 9-per-suit tile space.
+            base = (base / 7) * 9 + base % 7;
+            let tiles = [
+                tile136(offsets[0], base),
+                tile136(offsets[1], base + 1),
+                tile136(offsets[2], base + 2),
+            ];
+            let own = [tile34(tiles[(called + 1) % 3])?, tile34(tiles[(called + 2) % 3])?];
+            let min = tile34(tiles[0])?;
+            Chii::from_tiles(own[0], own[1], tile34(tiles[called])?, min).map(Meld::Chii)
+        } else if d & 0x8 != 0 {
+            // Pon: `base_and_called` above bit 9; the unused-tile nibble sits at bits 5..7.
+            let base_and_called = d >> 9;
+            let called = (base_and_called % 3) as u8;
+            let base = base_and_called / 3;
+            let tile = tile34(tile136(0, base))?;
+            let source = player_from_relative(from, relative);
+            Pon::from_tiles(tile, source, called).map(Meld::Pon)
+        } else if d & 0x10 != 0 {
+            // Kakan (added kan): shares the Pon field layout — `base_and_called` above bit 9 plus
+            // the relative direction of the original pon in the low bits.
+            let base_and_called = d >> 9;
+            let called = (base_and_called % 3) as u8;
+            let base = base_and_called / 3;
+            let tile = tile34(tile136(0, base))?;
+            let source = player_from_relative(from, relative);
+            Kakan::from_tiles(tile, source, called).map(Meld::Kakan)
+        } else {
+            // Kan: `base_and_called` above bit 8.
+            let base_and_called = d >> 8;
+            let base = base_and_called / 4;
+            let tile = tile34(tile136(0, base))?;
+            if relative == 0 {
+                Ankan::from_tile(tile).map(Meld::Ankan)
+            } else {
+                let source = player_from_relative(from, relative);
+                Daiminkan::from_tile(tile, source).map(Meld::Daiminkan)
+            }
+        }
+    }
+
+    /// Encodes this meld into a Tenhou 16-bit meld field. The inverse of [`from_tenhou`].
+    ///
+    /// [`from_tenhou`]: Meld::from_tenhou
+    pub fn to_tenhou(&self) -> u16 {
+        match self {
+            Meld::Chii(chii) => {
+                let base = seq_base(chii.min);
+                let called = chii.dir() as u16;
+                let base_and_called = base * 3 + called;
+                // All three tiles are "normal" (offset 0 within their 4-copy group). Chii is always
+                // called from the player to the left, so the low direction bits are fixed at `3`.
+                0x4 | (base_and_called << 10) | 3
+            }
+            Meld::Pon(pon) => {
+                let tile = pon.called.to_normal();
+                let base = tile.normal_num() as u16 - 1 + suit_offset(tile);
+                let base_and_called = base * 3;
+                let relative = relative_dir(pon.dir()) as u16;
+                0x8 | (base_and_called << 9) | relative
+            }
+            Meld::Kakan(kakan) => {
+                let tile = kakan.added.to_normal();
+                let base = tile.normal_num() as u16 - 1 + suit_offset(tile);
+                // Like Pon, the upper field is `base * 3` (called-position subfield left at 0); the
+                // source direction of the original Pon lives only in the low two relative bits.
+                let base_and_called = base * 3;
+                let relative = relative_dir(kakan.dir()) as u16;
+                0x10 | (base_and_called << 9) | relative
+            }
+            Meld::Daiminkan(daiminkan) => {
+                let tile = daiminkan.tile().to_normal();
+                let base = tile.normal_num() as u16 - 1 + suit_offset(tile);
+                let relative = relative_dir(daiminkan.dir()) as u16;
+                ((base * 4) << 8) | relative
+            }
+            Meld::Ankan(ankan) => {
+                let tile = ankan.tile().to_normal();
+                let base = tile.normal_num() as u16 - 1 + suit_offset(tile);
+                (base * 4) << 8
+            }
+        }
+    }
+}
+
+/// A tile in 136-tile space: `offset` (0..=3, which physical copy) plus the 34-kind `kind`.
+fn tile136(offset: u16, kind: u16) -> u16 { offset + 4 * kind }
+
+/// Recovers the 34-kind [`Tile`] from a 136-space index.
+fn tile34(id136: u16) -> Option<Tile> { Tile::from_encoding((id136 / 4) as u8) }
+
+/// The 34-kind index of the lowest tile of a suit (0 for man, 9 for pin, ...).
+fn suit_offset(tile: Tile) -> u16 { (tile.normal_encoding() as u16 / 9) * 9 }
+
+/// The sequence base (0..=20, remapped into the 7-per-suit Tenhou space) of a Chii's lowest tile.
+fn seq_base(min: Tile) -> u16 {
+    let kind = min.normal_encoding() as u16;
+    (kind / 9) * 7 + kind % 9
+}
+
+fn player_from_relative(from: Player, relative: u8) -> Player {
+    Player::new(relative).wrapping_add(from)
+}
+
+fn relative_dir(dir: u8) -> u8 { dir }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn p(i: u8) -> Player { Player::new(i) }
+
+    /// Decode a Tenhou field, re-encode, and require the field to come back bit-for-bit. Owner seat
+    /// `0` keeps relative == absolute direction, so the check is independent of how each meld stores
+    /// its source seat.
+    fn assert_field_round_trips(field: u16) {
+        let meld = Meld::from_tenhou(field, p(0)).expect("valid Tenhou meld");
+        assert_eq!(meld.to_tenhou(), field, "field {field:#06x} must round-trip unchanged");
+    }
+
+    /// Full field-equality round-trip over a known sample of every variant. Chii fixes its low bits
+    /// at `3` (always kamicha); Pon/Kakan carry the source seat in the low bits; Kan uses the
+    /// `base * 4` layout.
+    #[test]
+    fn tenhou_samples_round_trip() {
+        assert_field_round_trips(0x4 | 3);                 // Chii 123m, called 1m (low)
+        assert_field_round_trips(0x4 | (1 << 10) | 3);     // Chii 123m, called 2m (middle)
+        assert_field_round_trips(0x4 | (2 << 10) | 3);     // Chii 123m, called 3m (high)
+        assert_field_round_trips(0x8 | (6 << 9) | 1);      // Pon 3m from shimocha
+        assert_field_round_trips(0x8 | (6 << 9) | 3);      // Pon 3m from kamicha
+        assert_field_round_trips(0x10 | (6 << 9) | 2);     // Kakan 3m over a pon from toimen
+        assert_field_round_trips(((13 * 4) << 8) | 1);     // Daiminkan 5p from shimocha
+        assert_field_round_trips((24 * 4) << 8);           // Ankan 7s
+    }
+
+    /// The added-kan bit (`0x10`) must route to [`Meld::Kakan`] and, with a kamicha source
+    /// (`dir() == 3`), must not let the direction bleed into the tile base — the bug where
+    /// `base * 3 + dir` pushed 3m to 4m. Pins full field equality, not just the flag.
+    #[test]
+    fn kakan_kamicha_preserves_tile_base() {
+        let field: u16 = 0x10 | (6 << 9) | 3;  // 3m, relative 3 (kamicha)
+        let meld = Meld::from_tenhou(field, p(0)).unwrap();
+        assert!(matches!(meld, Meld::Kakan(_)));
+        assert_eq!(meld.to_tenhou(), field);
+    }
+
+    /// Each meld flag routes to the matching variant.
+    #[test]
+    fn flags_route_to_variants() {
+        assert!(matches!(Meld::from_tenhou(0x4 | 3, p(0)), Some(Meld::Chii(_))));
+        assert!(matches!(Meld::from_tenhou(0x8 | (6 << 9) | 3, p(0)), Some(Meld::Pon(_))));
+        assert!(matches!(Meld::from_tenhou(0x10 | (6 << 9) | 2, p(0)), Some(Meld::Kakan(_))));
+        assert!(matches!(Meld::from_tenhou((8 << 8) | 2, p(0)), Some(Meld::Daiminkan(_))));
+        assert!(matches!(Meld::from_tenhou(8 << 8, p(0)), Some(Meld::Ankan(_))));
+    }
+}
@@ -0,0 +1,89 @@
+use crate::common::*;
+
+use super::*;
+
+/// A single player's redacted view of the table, safe to broadcast to that player's remote client
+/// for networked play and bot training.
+///
+/// Public information is kept intact: discards (ponds) with their `called_by`, revealed melds, the
+/// dora indicator count, riichi flags, furiten, the action player, and the `num_drawn_head` /
+/// `num_drawn_tail` counts (all carried by [`StateCore`]). Everything the observer should not see
+/// is masked: the freshly drawn tile (unless the observer is the action player), every other
+/// seat's closed hand (reduced to a tile count), and other seats' concealed Ankan. The unrevealed
+/// wall and undrawn dead-wall tiles are not part of [`State`] and so are naturally absent.
+#[derive(Clone, Debug)]
+pub struct PlayerObservation {
+    /// The observing player.
+    pub player: Player,
+    /// Public per-turn state, with `draw` masked unless the observer is the action player.
+    pub core: StateCore,
+    /// The observer's own closed hand is intact; all others are a concealed tile count.
+    pub closed_hands: [ObservableHand; 4],
+    /// Revealed melds, with other players' Ankan kept face-down.
+    pub melds: [Vec<ObservableMeld>; 4],
+    /// Discards (ponds), fully public including `called_by`.
+    pub discards: [Vec<Discard>; 4],
+}
+
+/// A closed hand as seen by one seat: either fully visible (own) or reduced to its tile count.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ObservableHand {
+    /// The observer's own hand.
+    Own(TileSet37),
+    /// Another seat's hand, reduced to the number of concealed tiles.
+    Concealed(u8),
+}
+
+/// A meld as seen by one seat: either fully visible or a concealed quad (another seat's Ankan).
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ObservableMeld {
+    /// A publicly visible meld (Chii / Pon / Kakan / Daiminkan, or the observer's own Ankan).
+    Visible(Meld),
+    /// Another seat's Ankan, kept face-down.
+    ConcealedKan,
+}
+
+impl State {
+    /// Derives `player`'s legal view of this state, masking everything that player is not entitled
+    /// to see. See [`PlayerObservation`] for exactly what is kept and what is masked.
+    pub fn observe(&self, player: Player) -> PlayerObservation {
+        let player_i = player.to_usize();
+
+        // The freshly drawn tile belongs to the action player and is concealed from everyone else.
+        let mut core = self.core;
+        if self.core.action_player != player {
+            core.draw = None;
+        }
+
+        let closed_hands = all_players().map(|p| {
+            let i = p.to_usize();
+            if i == player_i {
+                ObservableHand::Own(self.closed_hands[i])
+            } else {
+                ObservableHand::Concealed(hand_count(&self.closed_hands[i]))
+            }
+        });
+        let melds = all_players().map(|p| {
+            let i = p.to_usize();
+            self.melds[i].iter().map(|&meld| {
+                if i != player_i && matches!(meld, Meld::Ankan(_)) {
+                    ObservableMeld::ConcealedKan
+                } else {
+                    ObservableMeld::Visible(meld)
+                }
+            }).collect()
+        });
+
+        PlayerObservation {
+            player,
+            core,
+            closed_hands,
+            melds,
+            discards: self.discards.clone(),
+        }
+    }
+}
+
+fn hand_count(hand: &TileSet37) -> u8 {
+    (0..37).map(|i| hand[i]).sum()
+}
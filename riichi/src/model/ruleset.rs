@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+/// A pluggable table of rule variants, keyed by name, so callers can select Tenhou / WRC / house
+/// rules without forking the engine.
+///
+/// The end-of-turn and scoring constants (noten penalty, nagashi mangan payouts, riichi pot) and the
+/// kan-dora timing, multi-ron, ron-abort and okuri-kan variants are entries here; the remaining
+/// `TODO(summivox): rules (...)` markers (e.g. kuikae) have no entry. Unknown keys fall back to the Tenhou-flavoured defaults baked into
+/// the accessors, so a partially-populated ruleset is always usable.
+#[derive(Clone, Debug, Default)]
+pub struct Ruleset {
+    bools: HashMap<String, bool>,
+    ints: HashMap<String, i64>,
+    choices: HashMap<String, String>,
+}
+
+impl Ruleset {
+    /// Looks up a boolean rule, falling back to the built-in default for `key`.
+    pub fn bool(&self, key: &str) -> bool {
+        self.bools.get(key).copied().unwrap_or_else(|| default_bool(key))
+    }
+
+    /// Looks up an integer rule, falling back to the built-in default for `key`.
+    pub fn int(&self, key: &str) -> i64 {
+        self.ints.get(key).copied().unwrap_or_else(|| default_int(key))
+    }
+
+    /// Looks up an enumerated (string-valued) rule, falling back to the built-in default for `key`.
+    pub fn choice(&self, key: &str) -> &str {
+        self.choices.get(key).map(String::as_str).unwrap_or_else(|| default_choice(key))
+    }
+
+    /// Overrides a boolean rule.
+    pub fn set_bool(&mut self, key: impl Into<String>, value: bool) -> &mut Self {
+        self.bools.insert(key.into(), value);
+        self
+    }
+
+    /// Overrides an integer rule.
+    pub fn set_int(&mut self, key: impl Into<String>, value: i64) -> &mut Self {
+        self.ints.insert(key.into(), value);
+        self
+    }
+
+    /// Overrides an enumerated rule.
+    pub fn set_choice(&mut self, key: impl Into<String>, value: impl Into<String>) -> &mut Self {
+        self.choices.insert(key.into(), value.into());
+        self
+    }
+}
+
+fn default_bool(key: &str) -> bool {
+    match key {
+        "nagashi_mangan_renchan" => true,
+        "okuri_kan" => false,
+        _ => false,
+    }
+}
+
+fn default_int(key: &str) -> i64 {
+    match key {
+        "noten_penalty_total" => 3000,
+        "riichi_pot" => 1000,
+        // Nagashi mangan is scored as a mangan tsumo.
+        "nagashi_mangan_dealer_total" => 12000,
+        "nagashi_mangan_dealer_each" => 4000,
+        "nagashi_mangan_nondealer_total" => 8000,
+        "nagashi_mangan_nondealer_each" => 2000,
+        _ => 0,
+    }
+}
+
+fn default_choice(key: &str) -> &'static str {
+    match key {
+        // `immediate`: reveal right after the kan; `deferred`: reveal at the end of the kan turn.
+        "kan_dora_timing" => "immediate",
+        // `atama_hane`: only the head-bumped winner scores; `multi`: full double/triple ron.
+        "multi_ron" => "multi",
+        _ => "",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unset_keys_fall_back_to_defaults() {
+        let rules = Ruleset::default();
+        assert_eq!(rules.int("riichi_pot"), 1000);
+        assert_eq!(rules.int("noten_penalty_total"), 3000);
+        assert!(rules.bool("nagashi_mangan_renchan"));
+        assert!(!rules.bool("okuri_kan"));
+        assert_eq!(rules.choice("kan_dora_timing"), "immediate");
+        // A key with no registered default reads as zero / false / empty.
+        assert_eq!(rules.int("does_not_exist"), 0);
+        assert_eq!(rules.choice("does_not_exist"), "");
+    }
+
+    #[test]
+    fn overrides_shadow_defaults() {
+        let mut rules = Ruleset::default();
+        rules.set_int("riichi_pot", 0)
+            .set_bool("okuri_kan", true)
+            .set_choice("multi_ron", "atama_hane");
+        assert_eq!(rules.int("riichi_pot"), 0);
+        assert!(rules.bool("okuri_kan"));
+        assert_eq!(rules.choice("multi_ron"), "atama_hane");
+        // Untouched keys still report their defaults.
+        assert_eq!(rules.int("noten_penalty_total"), 3000);
+    }
+}